@@ -0,0 +1,56 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, Records},
+    settings::object::Object,
+};
+
+/// A location which targets a column by its positional index.
+///
+/// Obtained via [`Locator::column_index`].
+///
+/// [`Locator::column_index`]: super::Locator::column_index
+#[derive(Debug, Clone, Copy)]
+pub struct ByColumnIndex {
+    index: usize,
+}
+
+impl ByColumnIndex {
+    /// Creates a new index based column locator.
+    pub const fn new(index: usize) -> Self {
+        Self { index }
+    }
+}
+
+impl<R> Object<R> for ByColumnIndex
+where
+    R: Records + ExactRecords,
+{
+    type Iter = std::option::IntoIter<Entity>;
+
+    fn cells(&self, records: &R) -> Self::Iter {
+        if self.index < records.count_columns() {
+            Some(Entity::Column(self.index)).into_iter()
+        } else {
+            None.into_iter()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        settings::{format::Format, location::Locator, Modify},
+        Table,
+    };
+
+    #[test]
+    fn locates_the_column_at_an_index() {
+        let data = vec!["ok"];
+
+        let table = Table::new(&data)
+            .with(Modify::new(Locator::column_index(0)).with(Format::content(|s| s.to_uppercase())))
+            .to_string();
+
+        assert!(table.contains("OK"));
+    }
+}
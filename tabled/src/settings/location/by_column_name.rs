@@ -0,0 +1,48 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records},
+    settings::object::Object,
+};
+
+/// A location which yields a column whose header matches a given text.
+///
+/// Obtained via [`Locator::column`].
+///
+/// [`Locator::column`]: super::Locator::column
+#[derive(Debug, Clone)]
+pub struct ByColumnName<S> {
+    text: S,
+}
+
+impl<S> ByColumnName<S>
+where
+    S: AsRef<str>,
+{
+    /// Creates a new header based column locator.
+    pub const fn new(text: S) -> Self {
+        Self { text }
+    }
+}
+
+impl<S, R> Object<R> for ByColumnName<S>
+where
+    S: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords,
+{
+    type Iter = std::vec::IntoIter<Entity>;
+
+    fn cells(&self, records: &R) -> Self::Iter {
+        let mut out = vec![];
+
+        let count_columns = records.count_columns();
+
+        for column in 0..count_columns {
+            let text = records.get_text((0, column));
+            if text == self.text.as_ref() {
+                out.push(Entity::Column(column));
+            }
+        }
+
+        out.into_iter()
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records},
+    settings::object::Object,
+};
+
+/// A location which yields every cell whose rendered content equals a given text.
+///
+/// Obtained via [`Locator::content`].
+///
+/// [`Locator::content`]: super::Locator::content
+#[derive(Debug, Clone)]
+pub struct ByContent<S> {
+    text: S,
+}
+
+impl<S> ByContent<S>
+where
+    S: AsRef<str>,
+{
+    /// Creates a new content based locator.
+    pub const fn new(text: S) -> Self {
+        Self { text }
+    }
+}
+
+impl<S, R> Object<R> for ByContent<S>
+where
+    S: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords,
+{
+    type Iter = std::vec::IntoIter<Entity>;
+
+    fn cells(&self, records: &R) -> Self::Iter {
+        let mut out = vec![];
+
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                let text = records.get_text((row, column));
+                if text == self.text.as_ref() {
+                    out.push(Entity::Cell(row, column));
+                }
+            }
+        }
+
+        out.into_iter()
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records},
+    settings::object::Object,
+};
+
+/// A location which yields every cell whose rendered content satisfies a predicate.
+///
+/// Obtained via [`Locator::by`].
+///
+/// [`Locator::by`]: super::Locator::by
+#[derive(Debug, Clone, Copy)]
+pub struct ByPredicate<F> {
+    f: F,
+}
+
+impl<F> ByPredicate<F>
+where
+    F: Fn(&str) -> bool,
+{
+    /// Creates a new predicate based locator.
+    pub const fn new(f: F) -> Self {
+        Self { f }
+    }
+}
+
+impl<F, R> Object<R> for ByPredicate<F>
+where
+    F: Fn(&str) -> bool,
+    R: Records + ExactRecords + PeekableRecords,
+{
+    type Iter = std::vec::IntoIter<Entity>;
+
+    fn cells(&self, records: &R) -> Self::Iter {
+        let mut out = vec![];
+
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                let text = records.get_text((row, column));
+                if (self.f)(text) {
+                    out.push(Entity::Cell(row, column));
+                }
+            }
+        }
+
+        out.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        settings::{format::Format, location::Locator, Modify},
+        Table,
+    };
+
+    #[test]
+    fn locates_cells_matching_a_predicate() {
+        let data = vec!["ok", "bad", "ok", "bad"];
+
+        let table = Table::new(&data)
+            .with(Modify::new(Locator::by(|s: &str| s == "bad")).with(Format::content(|s| s.to_uppercase())))
+            .to_string();
+
+        assert!(table.contains("BAD"));
+        assert!(table.contains("ok"));
+        assert!(!table.contains("OK"));
+    }
+}
@@ -0,0 +1,70 @@
+use regex::Regex;
+
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records},
+    settings::object::Object,
+};
+
+/// A location which yields every cell whose rendered content matches a regular expression.
+///
+/// Obtained via [`Locator::matches`].
+///
+/// [`Locator::matches`]: super::Locator::matches
+#[derive(Debug, Clone)]
+pub struct ByRegex {
+    re: Regex,
+}
+
+impl ByRegex {
+    /// Creates a new regex based locator.
+    pub fn new(re: Regex) -> Self {
+        Self { re }
+    }
+}
+
+impl<R> Object<R> for ByRegex
+where
+    R: Records + ExactRecords + PeekableRecords,
+{
+    type Iter = std::vec::IntoIter<Entity>;
+
+    fn cells(&self, records: &R) -> Self::Iter {
+        let mut out = vec![];
+
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                let text = records.get_text((row, column));
+                if self.re.is_match(text) {
+                    out.push(Entity::Cell(row, column));
+                }
+            }
+        }
+
+        out.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        settings::{format::Format, location::Locator, Modify},
+        Table,
+    };
+
+    #[test]
+    fn locates_cells_matching_a_regex() {
+        let data = vec!["ERROR: bad", "ok", "ERROR: worse", "ok"];
+
+        let table = Table::new(&data)
+            .with(Modify::new(Locator::matches("^ERROR").unwrap()).with(Format::content(|s| s.to_lowercase())))
+            .to_string();
+
+        assert!(table.contains("error: bad"));
+        assert!(table.contains("error: worse"));
+        assert!(table.contains("ok"));
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, PeekableRecords, Records},
+    settings::object::Object,
+};
+
+/// A location which finds a whole row by matching a value in any of its cells.
+///
+/// Obtained via [`Locator::row_content`].
+///
+/// [`Locator::row_content`]: super::Locator::row_content
+#[derive(Debug, Clone)]
+pub struct ByRowContent<S> {
+    text: S,
+}
+
+impl<S> ByRowContent<S>
+where
+    S: AsRef<str>,
+{
+    /// Creates a new content based row locator.
+    pub const fn new(text: S) -> Self {
+        Self { text }
+    }
+}
+
+impl<S, R> Object<R> for ByRowContent<S>
+where
+    S: AsRef<str>,
+    R: Records + ExactRecords + PeekableRecords,
+{
+    type Iter = std::vec::IntoIter<Entity>;
+
+    fn cells(&self, records: &R) -> Self::Iter {
+        let mut out = vec![];
+
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        for row in 0..count_rows {
+            for column in 0..count_columns {
+                let text = records.get_text((row, column));
+                if text == self.text.as_ref() {
+                    out.push(Entity::Row(row));
+                    break;
+                }
+            }
+        }
+
+        out.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        settings::{format::Format, location::Locator, Modify},
+        Table,
+    };
+
+    #[test]
+    fn locates_a_row_by_a_value_in_any_of_its_cells() {
+        let data = vec![("Total", "42"), ("Other", "1")];
+
+        let table = Table::new(&data)
+            .with(Modify::new(Locator::row_content("Total")).with(Format::content(|s| s.to_uppercase())))
+            .to_string();
+
+        assert!(table.contains("TOTAL"));
+        assert!(table.contains("42"));
+        assert!(table.contains("Other"));
+    }
+}
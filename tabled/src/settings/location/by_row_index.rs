@@ -0,0 +1,63 @@
+use crate::{
+    grid::config::Entity,
+    grid::records::{ExactRecords, Records},
+    settings::object::Object,
+};
+
+/// A location which targets a data row by its positional index.
+///
+/// The index is among the data rows; row `0` of the records (the header) is not counted.
+/// So `row_index(0)` targets the first data row, not the header.
+///
+/// Obtained via [`Locator::row_index`].
+///
+/// [`Locator::row_index`]: super::Locator::row_index
+#[derive(Debug, Clone, Copy)]
+pub struct ByRowIndex {
+    index: usize,
+}
+
+impl ByRowIndex {
+    /// Creates a new index based row locator.
+    pub const fn new(index: usize) -> Self {
+        Self { index }
+    }
+}
+
+impl<R> Object<R> for ByRowIndex
+where
+    R: Records + ExactRecords,
+{
+    type Iter = std::option::IntoIter<Entity>;
+
+    fn cells(&self, records: &R) -> Self::Iter {
+        let row = self.index + 1;
+
+        if row < records.count_rows() {
+            Some(Entity::Row(row)).into_iter()
+        } else {
+            None.into_iter()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        settings::{format::Format, location::Locator, Modify},
+        Table,
+    };
+
+    #[test]
+    fn locates_the_row_at_an_index() {
+        let data = vec!["first", "second", "third"];
+
+        let table = Table::new(&data)
+            .with(Modify::new(Locator::row_index(1)).with(Format::content(|s| s.to_uppercase())))
+            .to_string();
+
+        assert!(table.contains("SECOND"));
+        assert!(table.contains("first"));
+        assert!(table.contains("third"));
+    }
+}
@@ -1,4 +1,6 @@
-use super::{ByColumnName, ByContent};
+use super::{ByColumnIndex, ByColumnName, ByContent, ByPredicate, ByRowContent, ByRowIndex};
+#[cfg(feature = "regex")]
+use super::ByRegex;
 
 /// An abstract factory for locations, to be used to find different things on the table.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
@@ -20,4 +22,45 @@ impl Locator {
     {
         ByColumnName::new(text)
     }
+
+    /// Constructs a new location searcher for cells whose rendered content satisfies a predicate.
+    ///
+    /// ```rust,no_run
+    /// # use tabled::settings::location::Locator;
+    /// let is_negative = Locator::by(|text| text.starts_with('-'));
+    /// ```
+    pub fn by<F>(f: F) -> ByPredicate<F>
+    where
+        F: Fn(&str) -> bool,
+    {
+        ByPredicate::new(f)
+    }
+
+    /// Constructs a new location searcher for cells whose rendered content matches a regular expression.
+    #[cfg(feature = "regex")]
+    pub fn matches<S>(pattern: S) -> Result<ByRegex, regex::Error>
+    where
+        S: AsRef<str>,
+    {
+        regex::Regex::new(pattern.as_ref()).map(ByRegex::new)
+    }
+
+    /// Constructs a new location searcher for a column by its positional index.
+    pub const fn column_index(index: usize) -> ByColumnIndex {
+        ByColumnIndex::new(index)
+    }
+
+    /// Constructs a new location searcher for a data row by its positional index (`0` is the
+    /// first data row, not the header).
+    pub const fn row_index(index: usize) -> ByRowIndex {
+        ByRowIndex::new(index)
+    }
+
+    /// Constructs a new location searcher for a row containing a cell with the given content.
+    pub const fn row_content<S>(text: S) -> ByRowContent<S>
+    where
+        S: AsRef<str>,
+    {
+        ByRowContent::new(text)
+    }
 }
@@ -0,0 +1,22 @@
+//! This module contains object/[`Locator`] implementations for different types of location
+//! searches performed over a table's records.
+
+mod by_column_index;
+mod by_column_name;
+mod by_content;
+mod by_predicate;
+#[cfg(feature = "regex")]
+mod by_regex;
+mod by_row_content;
+mod by_row_index;
+mod locator;
+
+pub use by_column_index::ByColumnIndex;
+pub use by_column_name::ByColumnName;
+pub use by_content::ByContent;
+pub use by_predicate::ByPredicate;
+#[cfg(feature = "regex")]
+pub use by_regex::ByRegex;
+pub use by_row_content::ByRowContent;
+pub use by_row_index::ByRowIndex;
+pub use locator::Locator;
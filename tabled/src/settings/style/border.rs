@@ -0,0 +1,220 @@
+//! This module contains a configuration of a Border to set its character(s) via [`Border`].
+
+use core::marker::PhantomData;
+
+use crate::{
+    grid::config::{Border as GridBorder, ColoredConfig, Entity},
+    grid::records::{ExactRecords, Records},
+    settings::{style::On, CellOption, TableOption},
+};
+
+/// Border represents a border of a Cell.
+///
+/// ```text
+///                         top border
+///                             |
+///                             V
+/// corner top left ------> +_______+  <---- corner top right
+///                         |       |
+/// left border ----------> |  cell |  <---- right border
+///                         |       |
+/// corner bottom left ---> +_______+  <---- corner bottom right
+///                             ^
+///                             |
+///                        bottom border
+/// ```
+///
+/// Each side is a single character. Making a side several characters/rows thick would require
+/// the table's dimension/offset estimation to reserve the extra space, which isn't something
+/// this crate's grid currently supports, so `Border` doesn't expose a width setting that would
+/// have no visible effect.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use tabled::{Table, settings::{Modify, style::{Style, Border}, object::Rows}};
+/// # let data: Vec<&'static str> = Vec::new();
+/// let table = Table::new(&data)
+///     .with(Style::ascii())
+///     .with(Modify::new(Rows::single(0)).with(Border::new().top('x')));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Border<T, B, L, R> {
+    inner: GridBorder<char>,
+    _top: PhantomData<T>,
+    _bottom: PhantomData<B>,
+    _left: PhantomData<L>,
+    _right: PhantomData<R>,
+}
+
+impl<T, B, L, R> Border<T, B, L, R> {
+    pub(crate) const fn from_border(inner: GridBorder<char>) -> Border<T, B, L, R> {
+        Border {
+            inner,
+            _top: PhantomData,
+            _bottom: PhantomData,
+            _left: PhantomData,
+            _right: PhantomData,
+        }
+    }
+}
+
+impl Border<(), (), (), ()> {
+    /// Creates an empty border.
+    pub const fn new() -> Self {
+        Self::from_border(GridBorder::empty())
+    }
+}
+
+impl Border<On, On, On, On> {
+    /// This function constructs a cell borders with all sides set.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn full(
+        top: char,
+        bottom: char,
+        left: char,
+        right: char,
+        top_left: char,
+        top_right: char,
+        bottom_left: char,
+        bottom_right: char,
+    ) -> Self {
+        Self::from_border(GridBorder::full(
+            top,
+            bottom,
+            left,
+            right,
+            top_left,
+            top_right,
+            bottom_left,
+            bottom_right,
+        ))
+    }
+
+    /// This function constructs a cell borders with all sides's char set to a given character.
+    /// It behaves like [`Border::full`] with the same character set to each side.
+    pub const fn filled(c: char) -> Self {
+        Self::full(c, c, c, c, c, c, c, c)
+    }
+}
+
+impl<T, B, L, R> Border<T, B, L, R> {
+    /// Set a top border character.
+    pub fn top(mut self, c: char) -> Border<On, B, L, R> {
+        self.inner.top = Some(c);
+        Border::from_border(self.inner)
+    }
+
+    /// Set a bottom border character.
+    pub fn bottom(mut self, c: char) -> Border<T, On, L, R> {
+        self.inner.bottom = Some(c);
+        Border::from_border(self.inner)
+    }
+
+    /// Set a left border character.
+    pub fn left(mut self, c: char) -> Border<T, B, On, R> {
+        self.inner.left = Some(c);
+        Border::from_border(self.inner)
+    }
+
+    /// Set a right border character.
+    pub fn right(mut self, c: char) -> Border<T, B, L, On> {
+        self.inner.right = Some(c);
+        Border::from_border(self.inner)
+    }
+
+    /// Converts a border into a general data structure.
+    pub fn into_inner(self) -> GridBorder<char> {
+        self.inner
+    }
+}
+
+impl<B, R> Border<On, B, On, R> {
+    /// Set a top left intersection character.
+    pub fn corner_top_left(mut self, c: char) -> Self {
+        self.inner.left_top_corner = Some(c);
+        self
+    }
+}
+
+impl<B, L> Border<On, B, L, On> {
+    /// Set a top right intersection character.
+    pub fn corner_top_right(mut self, c: char) -> Self {
+        self.inner.right_top_corner = Some(c);
+        self
+    }
+}
+
+impl<T, R> Border<T, On, On, R> {
+    /// Set a bottom left intersection character.
+    pub fn corner_bottom_left(mut self, c: char) -> Self {
+        self.inner.left_bottom_corner = Some(c);
+        self
+    }
+}
+
+impl<T, L> Border<T, On, L, On> {
+    /// Set a bottom right intersection character.
+    pub fn corner_bottom_right(mut self, c: char) -> Self {
+        self.inner.right_bottom_corner = Some(c);
+        self
+    }
+}
+
+impl<T, B, L, R> From<Border<T, B, L, R>> for GridBorder<char> {
+    fn from(value: Border<T, B, L, R>) -> Self {
+        value.inner
+    }
+}
+
+impl<Data, T, B, L, R> CellOption<Data, ColoredConfig> for Border<T, B, L, R>
+where
+    Data: Records + ExactRecords,
+{
+    fn change(self, records: &mut Data, cfg: &mut ColoredConfig, entity: Entity) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        for pos in entity.iter(count_rows, count_columns) {
+            cfg.set_border(pos, self.inner.clone());
+        }
+    }
+}
+
+impl<Data, D, T, B, L, R> TableOption<Data, D, ColoredConfig> for Border<T, B, L, R>
+where
+    Data: Records + ExactRecords,
+{
+    fn change(self, records: &mut Data, cfg: &mut ColoredConfig, _: &mut D) {
+        let count_rows = records.count_rows();
+        let count_columns = records.count_columns();
+
+        for row in 0..count_rows {
+            for col in 0..count_columns {
+                cfg.set_border((row, col), self.inner.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        settings::{object::Rows, style::Style, Modify},
+        Table,
+    };
+
+    use super::Border;
+
+    #[test]
+    fn top_border_character_is_rendered() {
+        let data = vec!["hello"];
+
+        let table = Table::new(&data)
+            .with(Style::ascii())
+            .with(Modify::new(Rows::single(0)).with(Border::new().top('x')))
+            .to_string();
+
+        assert!(table.contains('x'));
+    }
+}
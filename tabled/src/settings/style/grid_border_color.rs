@@ -0,0 +1,204 @@
+//! This module contains a configuration of colors for a whole grid's borders via [`GridBorderColor`].
+
+use crate::{
+    grid::{
+        config::{Borders, ColoredConfig},
+        records::{ExactRecords, Records},
+    },
+    settings::{Color, TableOption},
+};
+
+/// `GridBorderColor` sets a color for every class of border glyph used across the whole table:
+/// the outer frame (`top`/`bottom`/`left`/`right` and their corners) as well as the interior
+/// split lines (`horizontal`/`vertical`) and the intersections where they cross
+/// (`top_intersection`, `bottom_intersection`, `left_intersection`, `right_intersection`
+/// and the central `intersection`).
+///
+/// Unlike [`BorderColor`], which colors the perimeter of each cell it is applied to,
+/// `GridBorderColor` is applied once as a [`TableOption`] and paints matching glyphs
+/// everywhere they occur in the table, e.g. the frame in one color and all interior
+/// separators in another.
+///
+/// [`BorderColor`]: crate::settings::style::BorderColor
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use tabled::{Table, settings::{style::GridBorderColor, Color}};
+/// # let data: Vec<&'static str> = Vec::new();
+/// let color = GridBorderColor::new()
+///     .top(Color::FG_RED)
+///     .bottom(Color::FG_RED)
+///     .left(Color::FG_RED)
+///     .right(Color::FG_RED)
+///     .horizontal(Color::FG_BLUE)
+///     .vertical(Color::FG_BLUE)
+///     .intersection(Color::FG_BLUE);
+///
+/// let table = Table::new(&data).with(color);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GridBorderColor {
+    borders: Borders<Color>,
+}
+
+impl GridBorderColor {
+    /// Creates an empty set of colors; nothing is painted unless set.
+    pub const fn new() -> Self {
+        Self {
+            borders: Borders::empty(),
+        }
+    }
+
+    /// Set a color for the top border line.
+    pub fn top(mut self, color: Color) -> Self {
+        self.borders.top = Some(color);
+        self
+    }
+
+    /// Set a color for the bottom border line.
+    pub fn bottom(mut self, color: Color) -> Self {
+        self.borders.bottom = Some(color);
+        self
+    }
+
+    /// Set a color for the left border line.
+    pub fn left(mut self, color: Color) -> Self {
+        self.borders.left = Some(color);
+        self
+    }
+
+    /// Set a color for the right border line.
+    pub fn right(mut self, color: Color) -> Self {
+        self.borders.right = Some(color);
+        self
+    }
+
+    /// Set a color for the top left corner.
+    pub fn corner_top_left(mut self, color: Color) -> Self {
+        self.borders.top_left = Some(color);
+        self
+    }
+
+    /// Set a color for the top right corner.
+    pub fn corner_top_right(mut self, color: Color) -> Self {
+        self.borders.top_right = Some(color);
+        self
+    }
+
+    /// Set a color for the bottom left corner.
+    pub fn corner_bottom_left(mut self, color: Color) -> Self {
+        self.borders.bottom_left = Some(color);
+        self
+    }
+
+    /// Set a color for the bottom right corner.
+    pub fn corner_bottom_right(mut self, color: Color) -> Self {
+        self.borders.bottom_right = Some(color);
+        self
+    }
+
+    /// Set a color for the interior horizontal split lines.
+    pub fn horizontal(mut self, color: Color) -> Self {
+        self.borders.horizontal = Some(color);
+        self
+    }
+
+    /// Set a color for the interior vertical split lines.
+    pub fn vertical(mut self, color: Color) -> Self {
+        self.borders.vertical = Some(color);
+        self
+    }
+
+    /// Set a color for the central intersection, where a horizontal and a vertical split cross.
+    pub fn intersection(mut self, color: Color) -> Self {
+        self.borders.intersection = Some(color);
+        self
+    }
+
+    /// Set a color for the intersection on the top border line, where a vertical split meets it.
+    pub fn top_intersection(mut self, color: Color) -> Self {
+        self.borders.top_intersection = Some(color);
+        self
+    }
+
+    /// Set a color for the intersection on the bottom border line, where a vertical split meets it.
+    pub fn bottom_intersection(mut self, color: Color) -> Self {
+        self.borders.bottom_intersection = Some(color);
+        self
+    }
+
+    /// Set a color for the intersection on the left border line, where a horizontal split meets it.
+    pub fn left_intersection(mut self, color: Color) -> Self {
+        self.borders.left_intersection = Some(color);
+        self
+    }
+
+    /// Set a color for the intersection on the right border line, where a horizontal split meets it.
+    pub fn right_intersection(mut self, color: Color) -> Self {
+        self.borders.right_intersection = Some(color);
+        self
+    }
+
+    /// Converts a color set into a general data structure.
+    pub fn into_inner(self) -> Borders<Color> {
+        self.borders
+    }
+}
+
+impl From<GridBorderColor> for Borders<Color> {
+    fn from(value: GridBorderColor) -> Self {
+        value.borders
+    }
+}
+
+impl<Data, D> TableOption<Data, D, ColoredConfig> for GridBorderColor
+where
+    Data: Records + ExactRecords,
+{
+    fn change(self, _: &mut Data, cfg: &mut ColoredConfig, _: &mut D) {
+        let borders_color = self.borders.convert();
+        cfg.set_borders_color(borders_color);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builder_sets_every_glyph_class() {
+        let colors = GridBorderColor::new()
+            .top(Color::FG_RED)
+            .bottom(Color::FG_RED)
+            .left(Color::FG_RED)
+            .right(Color::FG_RED)
+            .corner_top_left(Color::FG_RED)
+            .corner_top_right(Color::FG_RED)
+            .corner_bottom_left(Color::FG_RED)
+            .corner_bottom_right(Color::FG_RED)
+            .horizontal(Color::FG_BLUE)
+            .vertical(Color::FG_BLUE)
+            .intersection(Color::FG_BLUE)
+            .top_intersection(Color::FG_BLUE)
+            .bottom_intersection(Color::FG_BLUE)
+            .left_intersection(Color::FG_BLUE)
+            .right_intersection(Color::FG_BLUE)
+            .into_inner();
+
+        assert_eq!(colors.top, Some(Color::FG_RED));
+        assert_eq!(colors.top_left, Some(Color::FG_RED));
+        assert_eq!(colors.horizontal, Some(Color::FG_BLUE));
+        assert_eq!(colors.intersection, Some(Color::FG_BLUE));
+        assert_eq!(colors.left_intersection, Some(Color::FG_BLUE));
+    }
+
+    #[test]
+    fn unset_classes_stay_none() {
+        let colors = GridBorderColor::new().top(Color::FG_RED).into_inner();
+
+        assert_eq!(colors.top, Some(Color::FG_RED));
+        assert_eq!(colors.bottom, None);
+        assert_eq!(colors.horizontal, None);
+    }
+}
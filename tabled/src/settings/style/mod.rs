@@ -0,0 +1,14 @@
+//! This module contains [`Border`] and [`BorderColor`] builders for configuring a cell's
+//! border, plus [`GridBorderColor`] for coloring a whole table's border glyphs at once.
+
+mod border;
+mod border_color;
+mod grid_border_color;
+
+pub use border::Border;
+pub use border_color::BorderColor;
+pub use grid_border_color::GridBorderColor;
+
+/// A marker type which indicates that a particular side of a border has been set.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct On;